@@ -81,6 +81,11 @@ impl<T> Divide<T> for [T] {
 ///
 /// This struct is created by the [`divide`] method on [slices].
 ///
+/// This iterator also implements [`DoubleEndedIterator`], so it can be consumed from the
+/// back with `next_back` (or reversed with `.rev()`). Since the remainder is always
+/// distributed to the portions at the front, the portion returned by `next_back` has
+/// `len / n` elements rather than `len.div_ceil(n)`.
+///
 /// # Example
 ///
 /// ```
@@ -126,6 +131,21 @@ impl<'a, T> Iterator for Portion<'a, T> {
     }
 }
 
+impl<'a, T> DoubleEndedIterator for Portion<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.n == 0 {
+            None
+        } else {
+            let portionsz = self.v.len() / self.n;
+            self.n -= 1;
+            let (fst, snd) = self.v.split_at(self.v.len() - portionsz);
+            self.v = fst;
+            Some(snd)
+        }
+    }
+}
+
 /// An iterator over a slice in `n` mutable non-overlapping portions, starting at the beginning of the slice.
 ///
 /// The portions are mutable slices and do not overlap. If the length of the slice is not evenly divided
@@ -133,6 +153,11 @@ impl<'a, T> Iterator for Portion<'a, T> {
 ///
 /// This struct is created by the [`divide_mut`] method on [slices].
 ///
+/// This iterator also implements [`DoubleEndedIterator`], so it can be consumed from the
+/// back with `next_back` (or reversed with `.rev()`). Since the remainder is always
+/// distributed to the portions at the front, the portion returned by `next_back` has
+/// `len / n` elements rather than `len.div_ceil(n)`.
+///
 /// # Example
 ///
 /// ```
@@ -183,6 +208,21 @@ impl<'a, T> Iterator for PortionMut<'a, T> {
     }
 }
 
+impl<'a, T> DoubleEndedIterator for PortionMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.n == 0 {
+            None
+        } else {
+            let portionsz = self.v.len() / self.n;
+            self.n -= 1;
+            let (fst, snd) = unsafe { self.v.split_at_mut(self.v.len() - portionsz) };
+            self.v = fst;
+            Some(unsafe { &mut *snd })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +317,60 @@ mod tests {
         assert_eq!(iter.next(), Some(&mut [][..]));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn divide_next_back() {
+        let slice = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i'];
+        let mut iter = slice.divide(4);
+        assert_eq!(iter.next_back(), Some(&['h', 'i'][..]));
+        assert_eq!(iter.next_back(), Some(&['f', 'g'][..]));
+        assert_eq!(iter.next_back(), Some(&['d', 'e'][..]));
+        assert_eq!(iter.next_back(), Some(&['a', 'b', 'c'][..]));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn divide_next_back_smaller_size() {
+        let slice = ['a', 'b'];
+        let mut iter = slice.divide(3);
+        assert_eq!(iter.next_back(), Some(&[][..]));
+        assert_eq!(iter.next_back(), Some(&['b'][..]));
+        assert_eq!(iter.next_back(), Some(&['a'][..]));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn divide_meet_in_middle() {
+        let slice = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i'];
+        let mut iter = slice.divide(4);
+        assert_eq!(iter.next(), Some(&['a', 'b', 'c'][..]));
+        assert_eq!(iter.next_back(), Some(&['h', 'i'][..]));
+        assert_eq!(iter.next(), Some(&['d', 'e'][..]));
+        assert_eq!(iter.next_back(), Some(&['f', 'g'][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn divide_mut_next_back() {
+        let mut slice = [1, 2, 3, 4, 5];
+        slice.divide_mut(3).rev().for_each(|e| e[0] += 1);
+        let mut iter = slice.divide_mut(3);
+        assert_eq!(iter.next(), Some(&mut [2, 2][..]));
+        assert_eq!(iter.next(), Some(&mut [4, 4][..]));
+        assert_eq!(iter.next(), Some(&mut [6][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn divide_mut_meet_in_middle() {
+        let mut slice = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut iter = slice.divide_mut(4);
+        assert_eq!(iter.next(), Some(&mut [1, 2, 3][..]));
+        assert_eq!(iter.next_back(), Some(&mut [8, 9][..]));
+        assert_eq!(iter.next(), Some(&mut [4, 5][..]));
+        assert_eq!(iter.next_back(), Some(&mut [6, 7][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
 }